@@ -0,0 +1,527 @@
+//! Typed client for the Multi-Tenant API.
+//!
+//! Centralizes the request/response models and the `post(...).json().send().json()`
+//! boilerplate behind typed methods that return `Result<T, ApiError>`. Every call
+//! decodes the success body on 2xx and maps any non-2xx response into [`ApiError`]
+//! carrying the parsed `detail` and the HTTP status.
+
+use reqwest::blocking::{Client, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Default base URL of the API under test.
+pub const DEFAULT_BASE_URL: &str = "http://localhost:8000";
+
+/// Default base URL of the MailHog/MailCatcher-style capture API.
+pub const DEFAULT_MAIL_URL: &str = "http://localhost:1080";
+
+// ============================================================================
+// Models (matching Python API)
+// ============================================================================
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthResponse {
+    pub status: String,
+    pub timestamp: String,
+    pub environment: String,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegisterRequest {
+    pub tenant_name: String,
+    pub admin_email: String,
+    pub admin_username: String,
+    pub admin_password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegisterResponse {
+    pub message: String,
+    pub tenant_id: String,
+    pub admin_user_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenResponse {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub tenant_id: String,
+    pub username: String,
+    pub email: String,
+    pub full_name: String,
+    pub role: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateUserRequest {
+    pub username: String,
+    pub email: String,
+    pub full_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedUsers {
+    pub items: Vec<User>,
+    pub total: u64,
+    pub page: u64,
+    pub per_page: u64,
+    pub total_pages: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileMeta {
+    pub id: String,
+    pub tenant_id: String,
+    pub filename: String,
+    pub size: u64,
+    pub content_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileResponse {
+    pub message: String,
+    pub file: FileMeta,
+}
+
+// ============================================================================
+// Unified result type
+// ============================================================================
+
+/// Either a deserialized success body (2xx) or a decoded error (non-2xx).
+enum ApiResponse<T> {
+    Success(T),
+    Error { detail: String, status: u16 },
+}
+
+impl<T: DeserializeOwned> ApiResponse<T> {
+    /// Decode a blocking response: success body on 2xx, otherwise the parsed
+    /// `detail` (falling back to the status line, e.g. `404 Not Found`, when the
+    /// body isn't JSON).
+    fn decode(response: Response) -> reqwest::Result<Self> {
+        let status = response.status();
+        if status.is_success() {
+            Ok(ApiResponse::Success(response.json()?))
+        } else {
+            let code = status.as_u16();
+            let detail = response
+                .json::<ErrorResponse>()
+                .map(|e| e.detail)
+                .unwrap_or_else(|_| status.to_string());
+            Ok(ApiResponse::Error {
+                detail,
+                status: code,
+            })
+        }
+    }
+
+    fn into_result(self) -> Result<T, ApiError> {
+        match self {
+            ApiResponse::Success(value) => Ok(value),
+            ApiResponse::Error { detail, status } => Err(ApiError { detail, status }),
+        }
+    }
+}
+
+/// A failed API call: the HTTP `status` (0 for transport errors) and `detail`.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: u16,
+    pub detail: String,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error {}: {}", self.status, self.detail)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        ApiError {
+            status: err.status().map(|s| s.as_u16()).unwrap_or(0),
+            detail: err.to_string(),
+        }
+    }
+}
+
+// ============================================================================
+// Client
+// ============================================================================
+
+/// Typed HTTP client for the API. Construct with [`ApiClient::new`] for
+/// anonymous calls or [`ApiClient::with_token`] to carry a bearer token.
+pub struct ApiClient {
+    client: Client,
+    base_url: String,
+}
+
+impl ApiClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    pub fn with_token(base_url: &str, token: &str) -> Self {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+
+        Self {
+            client: Client::builder()
+                .default_headers(headers)
+                .build()
+                .unwrap(),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    pub fn register(&self, request: &RegisterRequest) -> Result<RegisterResponse, ApiError> {
+        let response = self
+            .client
+            .post(format!("{}/auth/register", self.base_url))
+            .json(request)
+            .send()?;
+        ApiResponse::decode(response)?.into_result()
+    }
+
+    pub fn login(&self, request: &LoginRequest) -> Result<TokenResponse, ApiError> {
+        let response = self
+            .client
+            .post(format!("{}/auth/login", self.base_url))
+            .json(request)
+            .send()?;
+        ApiResponse::decode(response)?.into_result()
+    }
+
+    pub fn create_user(&self, request: &CreateUserRequest) -> Result<User, ApiError> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/users", self.base_url))
+            .json(request)
+            .send()?;
+        ApiResponse::decode(response)?.into_result()
+    }
+
+    pub fn get_user(&self, id: &str) -> Result<User, ApiError> {
+        let response = self
+            .client
+            .get(format!("{}/api/v1/users/{}", self.base_url, id))
+            .send()?;
+        ApiResponse::decode(response)?.into_result()
+    }
+
+    /// List a single page of users.
+    pub fn list_users(&self, page: u64, per_page: u64) -> Result<PaginatedUsers, ApiError> {
+        let response = self
+            .client
+            .get(format!("{}/api/v1/users", self.base_url))
+            .query(&[("page", page), ("per_page", per_page)])
+            .send()?;
+        ApiResponse::decode(response)?.into_result()
+    }
+
+    /// Walk every page and return a flattened list of all users in the tenant.
+    pub fn list_all_users(&self) -> Result<Vec<User>, ApiError> {
+        const PER_PAGE: u64 = 100;
+        let mut all = Vec::new();
+        let mut page = 1;
+        loop {
+            let result = self.list_users(page, PER_PAGE)?;
+            let exhausted = result.items.is_empty() || page >= result.total_pages;
+            all.extend(result.items);
+            if exhausted {
+                break;
+            }
+            page += 1;
+        }
+        Ok(all)
+    }
+
+    /// Admin teardown: delete a tenant and all of its users.
+    pub fn delete_tenant(&self, tenant_id: &str) -> Result<(), ApiError> {
+        let response = self
+            .client
+            .delete(format!("{}/api/v1/tenants/{}", self.base_url, tenant_id))
+            .send()?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            ApiResponse::<()>::decode(response)?.into_result()
+        }
+    }
+}
+
+// ============================================================================
+// Test environment & fixtures
+// ============================================================================
+
+/// Test configuration loaded once from the environment, falling back to a
+/// `.env.test` file in the working directory and then to built-in defaults.
+#[derive(Debug)]
+pub struct TestEnv {
+    pub base_url: String,
+    pub mail_base: String,
+    pub admin_password: String,
+}
+
+impl TestEnv {
+    /// The process-wide environment, initialized on first access.
+    pub fn get() -> &'static TestEnv {
+        static ENV: OnceLock<TestEnv> = OnceLock::new();
+        ENV.get_or_init(TestEnv::load)
+    }
+
+    fn load() -> TestEnv {
+        let file = load_dotenv(".env.test");
+        let lookup = |key: &str, default: &str| -> String {
+            std::env::var(key)
+                .ok()
+                .or_else(|| file.get(key).cloned())
+                .unwrap_or_else(|| default.to_string())
+        };
+
+        TestEnv {
+            base_url: lookup("API_BASE", DEFAULT_BASE_URL),
+            mail_base: lookup("MAIL_BASE", DEFAULT_MAIL_URL),
+            admin_password: lookup("ADMIN_PASSWORD", "SecurePass123!"),
+        }
+    }
+}
+
+/// Parse a `KEY=VALUE` dotenv file, ignoring blank lines and `#` comments.
+/// A missing file yields an empty map.
+fn load_dotenv(path: &str) -> HashMap<String, String> {
+    let mut values = HashMap::new();
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return values;
+    };
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    values
+}
+
+/// A collision-proof suffix for tenant names, derived from the current time and
+/// a monotonic counter so concurrent tests never clash.
+pub fn unique_suffix() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0);
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}_{:x}", nanos, seq)
+}
+
+/// RAII guard for a freshly-registered tenant. Holds the admin tokens for the
+/// duration of a test and, on drop, deletes the tenant and its users so runs
+/// against a persistent backend stay repeatable.
+pub struct TenantFixture {
+    pub tenant_name: String,
+    pub tenant_id: String,
+    pub admin_email: String,
+    pub admin_username: String,
+    pub tokens: TokenResponse,
+    base_url: String,
+    admin_password: String,
+}
+
+impl TenantFixture {
+    /// Register a new tenant with a collision-proof name built from `prefix`,
+    /// log its admin in, and return the guard.
+    pub fn setup(env: &TestEnv, prefix: &str) -> Result<TenantFixture, ApiError> {
+        let client = ApiClient::new(&env.base_url);
+        let tenant_name = format!("{}_{}", prefix, unique_suffix());
+        let admin_email = format!("admin@{}.com", tenant_name);
+        let admin_username = format!("admin_{}", tenant_name);
+
+        let registered = client.register(&RegisterRequest {
+            tenant_name: tenant_name.clone(),
+            admin_email: admin_email.clone(),
+            admin_username: admin_username.clone(),
+            admin_password: env.admin_password.clone(),
+        })?;
+
+        let tokens = client.login(&LoginRequest {
+            username: admin_username.clone(),
+            password: env.admin_password.clone(),
+        })?;
+
+        Ok(TenantFixture {
+            tenant_name,
+            tenant_id: registered.tenant_id,
+            admin_email,
+            admin_username,
+            tokens,
+            base_url: env.base_url.clone(),
+            admin_password: env.admin_password.clone(),
+        })
+    }
+
+    pub fn access_token(&self) -> &str {
+        &self.tokens.access_token
+    }
+}
+
+impl Drop for TenantFixture {
+    fn drop(&mut self) {
+        // Best-effort teardown; a failure here shouldn't mask the test outcome.
+        // Re-authenticate first so cleanup still works if the test rotated or
+        // expired the access token held in `self.tokens`.
+        let anon = ApiClient::new(&self.base_url);
+        let token = anon
+            .login(&LoginRequest {
+                username: self.admin_username.clone(),
+                password: self.admin_password.clone(),
+            })
+            .map(|t| t.access_token)
+            .unwrap_or_else(|_| self.tokens.access_token.clone());
+
+        let client = ApiClient::with_token(&self.base_url, &token);
+        let _ = client.delete_tenant(&self.tenant_id);
+    }
+}
+
+// ============================================================================
+// Mail capture
+// ============================================================================
+
+/// A captured message as surfaced by the mail sink, flattened from MailHog's
+/// nested representation into the fields tests care about.
+#[derive(Debug)]
+pub struct CapturedEmail {
+    pub to: Vec<String>,
+    pub from: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// MailHog's `/api/v2/messages` payload (only the fields we consume).
+#[derive(Deserialize)]
+struct MailHogMessages {
+    items: Vec<MailHogMessage>,
+}
+
+#[derive(Deserialize)]
+struct MailHogMessage {
+    #[serde(rename = "Content")]
+    content: MailHogContent,
+}
+
+#[derive(Deserialize)]
+struct MailHogContent {
+    #[serde(rename = "Headers")]
+    headers: HashMap<String, Vec<String>>,
+    #[serde(rename = "Body")]
+    body: String,
+}
+
+impl MailHogContent {
+    fn header(&self, name: &str) -> Vec<String> {
+        self.headers.get(name).cloned().unwrap_or_default()
+    }
+}
+
+/// Client for a MailHog/MailCatcher-style HTTP capture API, used to assert that
+/// the backend actually sent registration/verification mail.
+pub struct MailClient {
+    client: Client,
+    base_url: String,
+}
+
+impl MailClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Poll for the most recently captured message addressed to `email`,
+    /// giving the backend a short grace period to deliver it.
+    pub fn latest_for(&self, email: &str) -> Result<CapturedEmail, Box<dyn std::error::Error>> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let messages: MailHogMessages = self
+                .client
+                .get(format!("{}/api/v2/messages", self.base_url))
+                .send()?
+                .json()?;
+
+            let found = messages.items.into_iter().find(|m| {
+                m.content
+                    .header("To")
+                    .iter()
+                    .any(|to| to.contains(email))
+            });
+
+            if let Some(message) = found {
+                let content = message.content;
+                return Ok(CapturedEmail {
+                    to: content.header("To"),
+                    from: content.header("From").first().cloned().unwrap_or_default(),
+                    subject: content.header("Subject").first().cloned().unwrap_or_default(),
+                    body: content.body,
+                });
+            }
+
+            if Instant::now() >= deadline {
+                return Err(format!("no captured email for {} within timeout", email).into());
+            }
+            std::thread::sleep(Duration::from_millis(250));
+        }
+    }
+
+    /// Delete all captured messages so a test starts from a clean mailbox.
+    pub fn clear(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.client
+            .delete(format!("{}/api/v1/messages", self.base_url))
+            .send()?
+            .error_for_status()?;
+        Ok(())
+    }
+}